@@ -9,19 +9,40 @@ use tauri::{LogicalSize, PhysicalSize};
 #[cfg(desktop)]
 use super::list_windows::resolve_window;
 
+/// A named size intent that can be resolved against the window's monitor
+/// instead of requiring the caller to know absolute pixel dimensions.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum WindowSizePreset {
+    /// 80% of the current monitor's work area.
+    Large,
+    /// 60% of the current monitor's work area.
+    Medium,
+    /// 40% of the current monitor's work area, centered.
+    Small,
+    /// An explicit size, always in logical pixels; with `logical: false` it is
+    /// converted to physical pixels using the window's scale factor.
+    Fixed { width: u32, height: u32 },
+    /// The window's current size multiplied by `factor`.
+    Scale { factor: f64 },
+}
+
 /// Parameters for resizing a window.
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ResizeWindowParams {
-    /// Width in pixels
-    pub width: u32,
-    /// Height in pixels
-    pub height: u32,
+    /// Width in pixels. Ignored when `preset` is present; required otherwise.
+    pub width: Option<u32>,
+    /// Height in pixels. Ignored when `preset` is present; required otherwise.
+    pub height: Option<u32>,
     /// Optional window label (defaults to "main")
     pub window_id: Option<String>,
     /// Whether to use logical (true) or physical (false) pixels. Defaults to logical.
     #[serde(default = "default_logical")]
     pub logical: bool,
+    /// A named size intent (`large`/`medium`/`small`/`fixed`/`scale`) resolved against
+    /// the window's current monitor. When present, overrides `width`/`height`.
+    pub preset: Option<WindowSizePreset>,
 }
 
 fn default_logical() -> bool {
@@ -36,9 +57,9 @@ pub struct ResizeWindowResult {
     pub success: bool,
     /// The window that was resized
     pub window_label: String,
-    /// The new width
+    /// The resolved width that was actually applied
     pub width: u32,
-    /// The new height
+    /// The resolved height that was actually applied
     pub height: u32,
     /// Whether logical pixels were used
     pub logical: bool,
@@ -46,6 +67,76 @@ pub struct ResizeWindowResult {
     pub error: Option<String>,
 }
 
+#[cfg(desktop)]
+fn fraction_of_work_area(fraction: f64, work_width: u32, work_height: u32) -> (u32, u32) {
+    (
+        (work_width as f64 * fraction).round() as u32,
+        (work_height as f64 * fraction).round() as u32,
+    )
+}
+
+/// Resolves a [`WindowSizePreset`] into a **physical** pixel width/height pair.
+/// Callers are responsible for converting to logical pixels (dividing by
+/// `scale_factor()`) when `logical` pixels were requested.
+#[cfg(desktop)]
+fn resolve_preset<R: Runtime>(
+    window: &tauri::WebviewWindow<R>,
+    preset: &WindowSizePreset,
+) -> Result<(u32, u32), String> {
+    match preset {
+        WindowSizePreset::Fixed { width, height } => {
+            // `width`/`height` are documented as logical; convert to physical so the
+            // result is in the same unit as every other branch.
+            let scale_factor = window
+                .scale_factor()
+                .map_err(|e| format!("Failed to read window scale factor: {e}"))?;
+            Ok((
+                (*width as f64 * scale_factor).round() as u32,
+                (*height as f64 * scale_factor).round() as u32,
+            ))
+        }
+        WindowSizePreset::Scale { factor } => {
+            let inner = window
+                .inner_size()
+                .map_err(|e| format!("Failed to read window size: {e}"))?;
+            Ok((
+                (inner.width as f64 * factor).round() as u32,
+                (inner.height as f64 * factor).round() as u32,
+            ))
+        }
+        WindowSizePreset::Large | WindowSizePreset::Medium | WindowSizePreset::Small => {
+            let monitor = match window
+                .current_monitor()
+                .map_err(|e| format!("Failed to query current monitor: {e}"))?
+            {
+                Some(monitor) => monitor,
+                None => window
+                    .primary_monitor()
+                    .map_err(|e| format!("Failed to query primary monitor: {e}"))?
+                    .ok_or_else(|| {
+                        "Could not resolve a size preset: no current or primary monitor available"
+                            .to_string()
+                    })?,
+            };
+
+            // Use the work area (monitor bounds minus reserved OS chrome like the
+            // taskbar/dock), not the full monitor bounds.
+            let work_area = monitor.work_area();
+            let fraction = match preset {
+                WindowSizePreset::Large => 0.8,
+                WindowSizePreset::Medium => 0.6,
+                WindowSizePreset::Small => 0.4,
+                _ => unreachable!("handled by outer match"),
+            };
+            Ok(fraction_of_work_area(
+                fraction,
+                work_area.width,
+                work_area.height,
+            ))
+        }
+    }
+}
+
 /// Resizes a window to the specified dimensions.
 ///
 /// # Arguments
@@ -62,6 +153,8 @@ pub struct ResizeWindowResult {
 ///
 /// - Uses logical pixels by default (respects display scaling)
 /// - Set `logical: false` to use physical pixels
+/// - `preset` resolves a named size intent (`large`/`medium`/`small`/`fixed`/`scale`) against
+///   the window's current monitor and overrides `width`/`height` when present
 /// - The resize may fail if the window has fixed size constraints
 /// - On mobile platforms (Android/iOS), this operation is not supported and returns an error
 #[cfg(desktop)]
@@ -75,14 +168,40 @@ pub async fn resize_window<R: Runtime>(
         .unwrap_or_else(|| "main".to_string());
     let window = resolve_window(&app, params.window_id)?;
 
+    let (width, height) = match &params.preset {
+        Some(preset) => {
+            let (physical_width, physical_height) = resolve_preset(&window, preset)?;
+            if params.logical {
+                let scale_factor = window
+                    .scale_factor()
+                    .map_err(|e| format!("Failed to read window scale factor: {e}"))?;
+                (
+                    (physical_width as f64 / scale_factor).round() as u32,
+                    (physical_height as f64 / scale_factor).round() as u32,
+                )
+            } else {
+                (physical_width, physical_height)
+            }
+        }
+        None => {
+            let width = params
+                .width
+                .ok_or_else(|| "Either `preset` or `width`/`height` must be provided".to_string())?;
+            let height = params
+                .height
+                .ok_or_else(|| "Either `preset` or `width`/`height` must be provided".to_string())?;
+            (width, height)
+        }
+    };
+
     // Check if window is resizable
     let is_resizable = window.is_resizable().unwrap_or(true);
     if !is_resizable {
         return Ok(ResizeWindowResult {
             success: false,
             window_label,
-            width: params.width,
-            height: params.height,
+            width,
+            height,
             logical: params.logical,
             error: Some("Window is not resizable".to_string()),
         });
@@ -90,25 +209,29 @@ pub async fn resize_window<R: Runtime>(
 
     // Perform the resize
     let result = if params.logical {
-        window.set_size(LogicalSize::new(params.width, params.height))
+        window.set_size(LogicalSize::new(width, height))
     } else {
-        window.set_size(PhysicalSize::new(params.width, params.height))
+        window.set_size(PhysicalSize::new(width, height))
     };
 
+    if let (Ok(()), Some(WindowSizePreset::Small)) = (&result, &params.preset) {
+        let _ = window.center();
+    }
+
     match result {
         Ok(()) => Ok(ResizeWindowResult {
             success: true,
             window_label,
-            width: params.width,
-            height: params.height,
+            width,
+            height,
             logical: params.logical,
             error: None,
         }),
         Err(e) => Ok(ResizeWindowResult {
             success: false,
             window_label,
-            width: params.width,
-            height: params.height,
+            width,
+            height,
             logical: params.logical,
             error: Some(format!("Failed to resize window: {e}")),
         }),
@@ -129,8 +252,8 @@ pub async fn resize_window<R: Runtime>(
     Ok(ResizeWindowResult {
         success: false,
         window_label,
-        width: params.width,
-        height: params.height,
+        width: params.width.unwrap_or_default(),
+        height: params.height.unwrap_or_default(),
         logical: params.logical,
         error: Some(
             "Window resizing is not supported on mobile platforms (Android/iOS). \
@@ -0,0 +1,106 @@
+//! Lightweight window/viewport metadata queries that avoid a full screenshot capture.
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Runtime};
+
+#[cfg(desktop)]
+use super::list_windows::resolve_window;
+
+/// Parameters for querying viewport metadata.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetViewportMetadataParams {
+    /// Optional window label (defaults to "main")
+    pub window_id: Option<String>,
+}
+
+/// Cheap, pixel-free description of a window's current size and state.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewportMetadataResult {
+    /// The window that was queried
+    pub window_label: String,
+    /// Current logical width
+    pub logical_width: u32,
+    /// Current logical height
+    pub logical_height: u32,
+    /// Current physical width
+    pub physical_width: u32,
+    /// Current physical height
+    pub physical_height: u32,
+    /// The window's scale factor
+    pub scale_factor: f64,
+    /// The current monitor's work area width, if a monitor could be resolved
+    pub monitor_width: Option<u32>,
+    /// The current monitor's work area height, if a monitor could be resolved
+    pub monitor_height: Option<u32>,
+    /// Whether the window can be resized
+    pub resizable: bool,
+    /// Whether the window is currently maximized
+    pub maximized: bool,
+    /// Whether the window is currently fullscreen
+    pub fullscreen: bool,
+}
+
+/// Returns the webview's current size, scale factor, monitor work area, and window
+/// flags without capturing any pixels. Useful for an agent deciding what `resize_window`
+/// or screenshot `max_width` value makes sense before paying for a full capture.
+#[cfg(desktop)]
+pub async fn get_viewport_metadata<R: Runtime>(
+    app: AppHandle<R>,
+    params: GetViewportMetadataParams,
+) -> Result<ViewportMetadataResult, String> {
+    let window_label = params
+        .window_id
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+    let window = resolve_window(&app, params.window_id)?;
+
+    let physical_size = window
+        .inner_size()
+        .map_err(|e| format!("Failed to read window size: {e}"))?;
+    let scale_factor = window
+        .scale_factor()
+        .map_err(|e| format!("Failed to read scale factor: {e}"))?;
+    let logical_size = physical_size.to_logical::<u32>(scale_factor);
+
+    let monitor = window
+        .current_monitor()
+        .map_err(|e| format!("Failed to query current monitor: {e}"))?;
+    let (monitor_width, monitor_height) = match monitor {
+        Some(monitor) => {
+            // Work area excludes reserved OS chrome (taskbar/dock), matching what a
+            // resize actually clamps to.
+            let work_area = monitor.work_area();
+            (Some(work_area.width), Some(work_area.height))
+        }
+        None => (None, None),
+    };
+
+    Ok(ViewportMetadataResult {
+        window_label,
+        logical_width: logical_size.width,
+        logical_height: logical_size.height,
+        physical_width: physical_size.width,
+        physical_height: physical_size.height,
+        scale_factor,
+        monitor_width,
+        monitor_height,
+        resizable: window.is_resizable().unwrap_or(true),
+        maximized: window.is_maximized().unwrap_or(false),
+        fullscreen: window.is_fullscreen().unwrap_or(false),
+    })
+}
+
+/// Mobile implementation - window/monitor metadata is not exposed the same way on
+/// Android/iOS, so this reports that explicitly rather than returning guessed values.
+#[cfg(mobile)]
+pub async fn get_viewport_metadata<R: Runtime>(
+    _app: AppHandle<R>,
+    params: GetViewportMetadataParams,
+) -> Result<ViewportMetadataResult, String> {
+    Err(format!(
+        "Viewport metadata is not available on mobile platforms (Android/iOS) for window '{}'",
+        params.window_id.unwrap_or_else(|| "main".to_string())
+    ))
+}
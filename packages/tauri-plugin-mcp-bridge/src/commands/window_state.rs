@@ -0,0 +1,169 @@
+//! Window attribute control beyond resizing.
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Runtime};
+
+#[cfg(desktop)]
+use super::list_windows::resolve_window;
+
+/// Parameters for setting window attributes. Only fields that are present are applied;
+/// absent fields leave the corresponding attribute untouched.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetWindowStateParams {
+    /// Optional window label (defaults to "main")
+    pub window_id: Option<String>,
+    /// Keep the window above all others
+    pub always_on_top: Option<bool>,
+    /// Show or hide the window's title bar/border
+    pub decorations: Option<bool>,
+    /// Enter or exit fullscreen
+    pub fullscreen: Option<bool>,
+    /// Maximize or restore the window
+    pub maximized: Option<bool>,
+    /// Minimize or restore the window
+    pub minimized: Option<bool>,
+    /// Show or hide the window entirely
+    pub visible: Option<bool>,
+}
+
+/// Resulting window state after applying the requested attributes.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowStateResult {
+    /// Whether every requested attribute was applied successfully
+    pub success: bool,
+    /// The window that was updated
+    pub window_label: String,
+    /// The requested `always_on_top` value, echoed back (Tauri exposes no getter for it)
+    pub always_on_top: Option<bool>,
+    /// Resolved `decorations` state, if queryable
+    pub decorations: Option<bool>,
+    /// Resolved `fullscreen` state, if queryable
+    pub fullscreen: Option<bool>,
+    /// Resolved `maximized` state, if queryable
+    pub maximized: Option<bool>,
+    /// Resolved `minimized` state, if queryable
+    pub minimized: Option<bool>,
+    /// Resolved `visible` state, if queryable
+    pub visible: Option<bool>,
+    /// Error message if one or more settings were rejected
+    pub error: Option<String>,
+}
+
+/// Applies the requested window attributes and returns the resulting state.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri application handle
+/// * `params` - Attributes to apply; only present fields are touched
+///
+/// # Notes
+///
+/// - Attributes are applied independently; if one is rejected by the OS, the rest
+///   are still attempted and the first error is surfaced via `error`
+/// - On mobile platforms (Android/iOS), this operation is not supported and returns
+///   an error explaining which attributes the OS controls instead
+#[cfg(desktop)]
+pub async fn set_window_state<R: Runtime>(
+    app: AppHandle<R>,
+    params: SetWindowStateParams,
+) -> Result<WindowStateResult, String> {
+    let window_label = params
+        .window_id
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+    let window = resolve_window(&app, params.window_id)?;
+
+    let mut error: Option<String> = None;
+
+    if let Some(always_on_top) = params.always_on_top {
+        if let Err(e) = window.set_always_on_top(always_on_top) {
+            error.get_or_insert(format!("Failed to set always_on_top: {e}"));
+        }
+    }
+
+    if let Some(decorations) = params.decorations {
+        if let Err(e) = window.set_decorations(decorations) {
+            error.get_or_insert(format!("Failed to set decorations: {e}"));
+        }
+    }
+
+    if let Some(fullscreen) = params.fullscreen {
+        if let Err(e) = window.set_fullscreen(fullscreen) {
+            error.get_or_insert(format!("Failed to set fullscreen: {e}"));
+        }
+    }
+
+    if let Some(maximized) = params.maximized {
+        let result = if maximized {
+            window.maximize()
+        } else {
+            window.unmaximize()
+        };
+        if let Err(e) = result {
+            error.get_or_insert(format!("Failed to set maximized: {e}"));
+        }
+    }
+
+    if let Some(minimized) = params.minimized {
+        let result = if minimized {
+            window.minimize()
+        } else {
+            window.unminimize()
+        };
+        if let Err(e) = result {
+            error.get_or_insert(format!("Failed to set minimized: {e}"));
+        }
+    }
+
+    if let Some(visible) = params.visible {
+        let result = if visible { window.show() } else { window.hide() };
+        if let Err(e) = result {
+            error.get_or_insert(format!("Failed to set visible: {e}"));
+        }
+    }
+
+    Ok(WindowStateResult {
+        success: error.is_none(),
+        window_label,
+        // Tauri exposes `set_always_on_top()` but no getter, so echo back what was
+        // requested rather than trying to query the resulting state.
+        always_on_top: params.always_on_top,
+        decorations: window.is_decorated().ok(),
+        fullscreen: window.is_fullscreen().ok(),
+        maximized: window.is_maximized().ok(),
+        minimized: window.is_minimized().ok(),
+        visible: window.is_visible().ok(),
+        error,
+    })
+}
+
+/// Mobile implementation - returns unsupported error with clear explanation for the agent.
+#[cfg(mobile)]
+pub async fn set_window_state<R: Runtime>(
+    _app: AppHandle<R>,
+    params: SetWindowStateParams,
+) -> Result<WindowStateResult, String> {
+    let window_label = params
+        .window_id
+        .clone()
+        .unwrap_or_else(|| "main".to_string());
+
+    Ok(WindowStateResult {
+        success: false,
+        window_label,
+        always_on_top: None,
+        decorations: None,
+        fullscreen: None,
+        maximized: None,
+        minimized: None,
+        visible: None,
+        error: Some(
+            "Window attribute control is not supported on mobile platforms (Android/iOS). \
+             always_on_top, decorations, fullscreen, maximized, minimized and visible \
+             are controlled by the operating system."
+                .to_string(),
+        ),
+    })
+}
@@ -1,7 +1,112 @@
 //! Simple port discovery for multiple Tauri instances.
 //!
 //! This module provides a lightweight mechanism for multiple Tauri apps
-//! to coexist on the same machine by finding available ports dynamically.
+//! to coexist on the same machine by finding available ports dynamically,
+//! and for MCP clients to discover which port each running instance bound to.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+/// A discovery record describing a single running instance, written after its
+/// WebSocket server has successfully bound to a port.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct InstanceRecord {
+    /// Process ID of the running instance
+    pub pid: u32,
+    /// The WebSocket port that was bound
+    pub port: u16,
+    /// The address the WebSocket server is bound to
+    pub bind_address: String,
+    /// The window/app title, for display when multiple instances are listed
+    pub title: String,
+    /// Unix timestamp (seconds) of when the record was written
+    pub started_at: u64,
+}
+
+/// The well-known directory discovery records are written to, under the OS temp
+/// directory (shared by all users on systems where that is `/tmp`).
+fn instances_dir() -> PathBuf {
+    env::temp_dir().join("tauri-mcp-bridge").join("instances")
+}
+
+fn record_path(pid: u32) -> PathBuf {
+    instances_dir().join(format!("{pid}.json"))
+}
+
+/// Writes a discovery record for the current process. Call this once the WebSocket
+/// server has successfully bound to `port`, and call [`remove_discovery_record`] on
+/// shutdown to clean it up.
+pub fn write_discovery_record(port: u16, bind_address: &str, title: &str) -> std::io::Result<()> {
+    let dir = instances_dir();
+    fs::create_dir_all(&dir)?;
+
+    let record = InstanceRecord {
+        pid: std::process::id(),
+        port,
+        bind_address: bind_address.to_string(),
+        title: title.to_string(),
+        started_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+    };
+
+    let json = serde_json::to_string_pretty(&record)?;
+    fs::write(record_path(record.pid), json)
+}
+
+/// Removes the current process's discovery record. Safe to call even if no record
+/// was ever written.
+pub fn remove_discovery_record() {
+    let _ = fs::remove_file(record_path(std::process::id()));
+}
+
+/// Returns whether a process with the given PID is still alive.
+fn is_process_alive(pid: u32) -> bool {
+    let mut system = sysinfo::System::new();
+    let sys_pid = sysinfo::Pid::from_u32(pid);
+    system.refresh_process(sys_pid);
+    system.process(sys_pid).is_some()
+}
+
+/// Lists all live running instances by reading discovery records from the instances
+/// directory, pruning any whose PID no longer exists (e.g. a crashed instance that
+/// never got a chance to remove its own record).
+pub fn list_running_instances() -> Vec<InstanceRecord> {
+    let entries = match fs::read_dir(instances_dir()) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut instances = Vec::new();
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+            continue;
+        }
+
+        let Ok(contents) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let Ok(record) = serde_json::from_str::<InstanceRecord>(&contents) else {
+            continue;
+        };
+
+        if is_process_alive(record.pid) {
+            instances.push(record);
+        } else {
+            let _ = fs::remove_file(&path);
+        }
+    }
+
+    instances
+}
 
 /// Finds an available port for the WebSocket server.
 ///
@@ -59,4 +164,41 @@ mod tests {
         assert!(port >= 9400);
         assert!(port < 9500);
     }
+
+    #[test]
+    fn test_discovery_record_round_trip() {
+        write_discovery_record(9223, "127.0.0.1", "Test Window").unwrap();
+
+        let own_pid = std::process::id();
+        let instances = list_running_instances();
+        assert!(instances.iter().any(|record| record.pid == own_pid));
+
+        remove_discovery_record();
+        let instances = list_running_instances();
+        assert!(!instances.iter().any(|record| record.pid == own_pid));
+    }
+
+    #[test]
+    fn test_list_running_instances_prunes_dead_pid() {
+        // A PID astronomically unlikely to be alive on any machine running this test.
+        let dead_pid: u32 = 0xFFFF_FFF0;
+        let record = InstanceRecord {
+            pid: dead_pid,
+            port: 9223,
+            bind_address: "127.0.0.1".to_string(),
+            title: "Stale Window".to_string(),
+            started_at: 0,
+        };
+
+        fs::create_dir_all(instances_dir()).unwrap();
+        fs::write(
+            record_path(dead_pid),
+            serde_json::to_string_pretty(&record).unwrap(),
+        )
+        .unwrap();
+
+        let instances = list_running_instances();
+        assert!(!instances.iter().any(|r| r.pid == dead_pid));
+        assert!(!record_path(dead_pid).exists());
+    }
 }
@@ -1,8 +1,10 @@
 use std::env;
 use std::io::Cursor;
+use std::path::Path;
 
 use image::imageops::FilterType;
 use image::ImageFormat;
+use serde::Serialize;
 use tauri::{Runtime, WebviewWindow};
 
 // Platform-specific modules
@@ -76,6 +78,111 @@ fn convert_to_jpeg(png_data: Vec<u8>, quality: u8) -> Result<Vec<u8>, Screenshot
     Ok(buffer.into_inner())
 }
 
+/// Convert PNG data to WebP format with specified lossy quality.
+fn convert_to_webp(png_data: Vec<u8>, quality: u8) -> Result<Vec<u8>, ScreenshotError> {
+    let img = image::load_from_memory(&png_data)
+        .map_err(|e| ScreenshotError::EncodeFailed(format!("Failed to decode PNG: {e}")))?;
+
+    let rgba = img.to_rgba8();
+    let encoder = webp::Encoder::from_rgba(&rgba, rgba.width(), rgba.height());
+    let encoded = encoder.encode(quality as f32);
+
+    Ok(encoded.to_vec())
+}
+
+/// Resample image data to match a requested effective scale factor, upscaling if
+/// needed (unlike [`resize_if_needed`], which never upscales). Returns the data
+/// unchanged if `target_scale` matches the window's actual scale factor.
+fn apply_scale_factor<R: Runtime>(
+    window: &WebviewWindow<R>,
+    data: Vec<u8>,
+    target_scale: f64,
+) -> Result<Vec<u8>, ScreenshotError> {
+    let actual_scale = window.scale_factor().map_err(|e| {
+        ScreenshotError::ResizeFailed(format!("Failed to read window scale factor: {e}"))
+    })?;
+
+    if (target_scale - actual_scale).abs() < f64::EPSILON {
+        return Ok(data);
+    }
+
+    let img = image::load_from_memory(&data)
+        .map_err(|e| ScreenshotError::ResizeFailed(format!("Failed to decode image: {e}")))?;
+
+    let ratio = target_scale / actual_scale;
+    let new_width = (img.width() as f64 * ratio).round().max(1.0) as u32;
+    let new_height = (img.height() as f64 * ratio).round().max(1.0) as u32;
+
+    // Upscaling is intentional here: this mode captures at a higher effective
+    // resolution than the real display, unlike max_width's never-upscale guarantee.
+    let resampled = img.resize_exact(new_width, new_height, FilterType::Lanczos3);
+
+    let mut buffer = Cursor::new(Vec::new());
+    resampled
+        .write_to(&mut buffer, ImageFormat::Png)
+        .map_err(|e| ScreenshotError::ResizeFailed(format!("Failed to encode PNG: {e}")))?;
+
+    Ok(buffer.into_inner())
+}
+
+/// Resize a decoded image to exactly `new_width` x `new_height` using a SIMD-accelerated
+/// Lanczos3 filter. This is the default, hot path for large 4K/5K webview captures;
+/// enable the `pure_image_resize` feature to opt out on platforms where the SIMD
+/// crate won't build.
+#[cfg(not(feature = "pure_image_resize"))]
+fn resize_rgba(
+    img: &image::DynamicImage,
+    new_width: u32,
+    new_height: u32,
+) -> Result<image::DynamicImage, ScreenshotError> {
+    use fast_image_resize as fr;
+    use std::num::NonZeroU32;
+
+    let rgba = img.to_rgba8();
+    let (src_width, src_height) = (rgba.width(), rgba.height());
+
+    let src_image = fr::Image::from_vec_u8(
+        NonZeroU32::new(src_width)
+            .ok_or_else(|| ScreenshotError::ResizeFailed("Source image has zero width".into()))?,
+        NonZeroU32::new(src_height)
+            .ok_or_else(|| ScreenshotError::ResizeFailed("Source image has zero height".into()))?,
+        rgba.into_raw(),
+        fr::PixelType::U8x4,
+    )
+    .map_err(|e| ScreenshotError::ResizeFailed(format!("Failed to view source image: {e}")))?;
+
+    let mut dst_image = fr::Image::new(
+        NonZeroU32::new(new_width)
+            .ok_or_else(|| ScreenshotError::ResizeFailed("Target width is zero".into()))?,
+        NonZeroU32::new(new_height)
+            .ok_or_else(|| ScreenshotError::ResizeFailed("Target height is zero".into()))?,
+        fr::PixelType::U8x4,
+    );
+
+    let mut resizer = fr::Resizer::new(fr::ResizeAlg::Convolution(fr::FilterType::Lanczos3));
+    resizer
+        .resize(&src_image.view(), &mut dst_image.view_mut())
+        .map_err(|e| ScreenshotError::ResizeFailed(format!("Failed to resize image: {e}")))?;
+
+    let buffer = image::RgbaImage::from_raw(new_width, new_height, dst_image.into_vec())
+        .ok_or_else(|| {
+            ScreenshotError::ResizeFailed("Resized buffer had an unexpected size".to_string())
+        })?;
+
+    Ok(image::DynamicImage::ImageRgba8(buffer))
+}
+
+/// Pure-`image` fallback for platforms where the SIMD `fast_image_resize` crate won't
+/// build. Opt in with the `pure_image_resize` feature.
+#[cfg(feature = "pure_image_resize")]
+fn resize_rgba(
+    img: &image::DynamicImage,
+    new_width: u32,
+    new_height: u32,
+) -> Result<image::DynamicImage, ScreenshotError> {
+    Ok(img.resize_exact(new_width, new_height, FilterType::Lanczos3))
+}
+
 /// Resize image data if it exceeds max_width, preserving aspect ratio.
 /// Returns the original data if no resizing is needed.
 /// Note: This function only handles resizing, not format conversion.
@@ -98,8 +205,7 @@ fn resize_if_needed(
     let scale = max_width as f64 / current_width as f64;
     let new_height = (current_height as f64 * scale).round() as u32;
 
-    // Resize using Lanczos3 for high quality
-    let resized = img.resize(max_width, new_height, FilterType::Lanczos3);
+    let resized = resize_rgba(&img, max_width, new_height)?;
 
     // Encode back to PNG (format conversion happens later)
     let mut buffer = Cursor::new(Vec::new());
@@ -110,20 +216,22 @@ fn resize_if_needed(
     Ok(buffer.into_inner())
 }
 
-/// Convert image data to the requested format (PNG or JPEG).
+/// Convert image data to the requested format (PNG, JPEG, or WebP).
 /// If data is already in the requested format, returns it unchanged.
 fn convert_format(
     data: Vec<u8>,
     format: &str,
     quality: u8,
 ) -> Result<Vec<u8>, ScreenshotError> {
-    // If PNG is requested, return as-is (platform implementations return PNG)
-    if format == "png" {
-        return Ok(data);
+    match format {
+        // Platform implementations return PNG, so this is a no-op.
+        "png" => Ok(data),
+        "jpeg" => convert_to_jpeg(data, quality),
+        "webp" => convert_to_webp(data, quality),
+        other => Err(ScreenshotError::EncodeFailed(format!(
+            "Unsupported screenshot format: {other}"
+        ))),
     }
-
-    // Convert to JPEG
-    convert_to_jpeg(data, quality)
 }
 
 /// Platform-specific screenshot implementation trait
@@ -134,13 +242,59 @@ pub trait PlatformScreenshot {
     ) -> Result<Screenshot, ScreenshotError>;
 }
 
-/// Capture a screenshot of the current viewport using platform-specific APIs
-pub async fn capture_viewport_screenshot<R: Runtime>(
+/// Result of writing a captured screenshot directly to disk.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScreenshotFileResult {
+    /// The absolute path the screenshot was written to
+    pub path: String,
+    /// The number of encoded bytes written
+    pub bytes_written: usize,
+    /// The final image width in pixels
+    pub width: u32,
+    /// The final image height in pixels
+    pub height: u32,
+}
+
+/// Result of capturing a screenshot as a base64 data URL.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScreenshotDataUrlResult {
+    /// The `data:` URL containing the base64-encoded image
+    pub data_url: String,
+    /// The final image width in pixels
+    pub width: u32,
+    /// The final image height in pixels
+    pub height: u32,
+}
+
+/// Infers an output format from a file extension, returning `None` for
+/// unrecognized or missing extensions so the caller can fall back to the
+/// explicit `format` argument.
+fn format_from_extension(path: &Path) -> Option<&'static str> {
+    match path
+        .extension()
+        .and_then(|ext| ext.to_str())?
+        .to_lowercase()
+        .as_str()
+    {
+        "png" => Some("png"),
+        "jpg" | "jpeg" => Some("jpeg"),
+        "webp" => Some("webp"),
+        _ => None,
+    }
+}
+
+/// Capture, resample, resize, and encode a screenshot, returning the final bytes,
+/// MIME type, and resolved pixel dimensions. Shared by the base64 and file-output
+/// entry points below.
+async fn capture_and_process<R: Runtime>(
     window: &WebviewWindow<R>,
     format: &str,
     quality: u8,
     max_width: Option<u32>,
-) -> Result<String, ScreenshotError> {
+    scale_factor: Option<f64>,
+) -> Result<(Vec<u8>, &'static str, u32, u32), ScreenshotError> {
     // Dispatch to platform-specific implementation
     #[cfg(target_os = "macos")]
     let screenshot = macos::capture_viewport(window)?;
@@ -166,26 +320,96 @@ pub async fn capture_viewport_screenshot<R: Runtime>(
     )))]
     return Err(ScreenshotError::PlatformUnsupported);
 
+    // Apply the requested effective scale factor (hidpi mode), upscaling if needed
+    let scaled_data = match scale_factor {
+        Some(target_scale) => apply_scale_factor(window, screenshot.data, target_scale)?,
+        None => screenshot.data,
+    };
+
     // Apply max_width constraint if specified (param or env var)
     let effective_max_width = get_effective_max_width(max_width);
     let resized_data = match effective_max_width {
-        Some(max_w) => resize_if_needed(screenshot.data, max_w)?,
-        None => screenshot.data,
+        Some(max_w) => resize_if_needed(scaled_data, max_w)?,
+        None => scaled_data,
+    };
+
+    let (final_width, final_height) = {
+        let img = image::load_from_memory(&resized_data).map_err(|e| {
+            ScreenshotError::EncodeFailed(format!("Failed to read image dimensions: {e}"))
+        })?;
+        (img.width(), img.height())
     };
 
-    // Convert to the requested format (PNG data from platform -> JPEG if needed)
+    // Convert to the requested format (PNG data from platform -> JPEG/WebP if needed)
     let final_data = convert_format(resized_data, format, quality)?;
 
-    // Convert to base64 data URL
-    let mime_type = if format == "jpeg" {
-        "image/jpeg"
-    } else {
-        "image/png"
+    let mime_type = match format {
+        "jpeg" => "image/jpeg",
+        "webp" => "image/webp",
+        _ => "image/png",
     };
 
+    Ok((final_data, mime_type, final_width, final_height))
+}
+
+/// Capture a screenshot of the current viewport using platform-specific APIs.
+///
+/// `scale_factor`, when provided, captures at an effective pixels-per-point
+/// resolution that may differ from (and upscale past) the real display, for
+/// crisp, display-independent output.
+pub async fn capture_viewport_screenshot<R: Runtime>(
+    window: &WebviewWindow<R>,
+    format: &str,
+    quality: u8,
+    max_width: Option<u32>,
+    scale_factor: Option<f64>,
+) -> Result<ScreenshotDataUrlResult, ScreenshotError> {
+    let (final_data, mime_type, width, height) =
+        capture_and_process(window, format, quality, max_width, scale_factor).await?;
+
     use base64::Engine as _;
     let base64_data = base64::engine::general_purpose::STANDARD.encode(&final_data);
     let data_url = format!("data:{mime_type};base64,{base64_data}");
 
-    Ok(data_url)
+    Ok(ScreenshotDataUrlResult {
+        data_url,
+        width,
+        height,
+    })
+}
+
+/// Capture a screenshot and write the encoded bytes directly to `path` instead of
+/// returning a base64 data URL, avoiding multi-megabyte MCP responses for large or
+/// high-DPI captures. The output format is inferred from `path`'s extension, falling
+/// back to `format` when the extension is missing or unrecognized.
+pub async fn capture_viewport_to_file<R: Runtime>(
+    window: &WebviewWindow<R>,
+    path: &str,
+    format: &str,
+    quality: u8,
+    max_width: Option<u32>,
+    scale_factor: Option<f64>,
+) -> Result<ScreenshotFileResult, ScreenshotError> {
+    let dest = Path::new(path);
+    let effective_format = format_from_extension(dest).unwrap_or(format);
+
+    let (final_data, _mime_type, width, height) =
+        capture_and_process(window, effective_format, quality, max_width, scale_factor).await?;
+
+    std::fs::write(dest, &final_data).map_err(|e| {
+        ScreenshotError::EncodeFailed(format!("Failed to write screenshot to {path}: {e}"))
+    })?;
+
+    let absolute_path = dest
+        .canonicalize()
+        .unwrap_or_else(|_| dest.to_path_buf())
+        .to_string_lossy()
+        .to_string();
+
+    Ok(ScreenshotFileResult {
+        path: absolute_path,
+        bytes_written: final_data.len(),
+        width,
+        height,
+    })
 }